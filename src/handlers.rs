@@ -1,11 +1,17 @@
 mod commands;
+mod cors;
+pub(crate) mod middleware;
+mod openapi;
 mod request_info;
 mod route;
+mod timeout;
 mod utils;
 
+use std::path::Path;
+
 use async_trait::async_trait;
 
-use hyper::{http::Response, Body};
+use hyper::{http::Method, http::Response, Body};
 
 use crate::request::HttpRequest;
 
@@ -14,12 +20,28 @@ pub trait RequestHandler: Send + Sync {
     async fn handle(&self, request: &HttpRequest) -> Response<Body>;
 }
 
-pub fn create_handlers() -> anyhow::Result<Box<dyn RequestHandler>> {
+fn base_routes() -> anyhow::Result<Vec<route::RouteInfo>> {
     let mut routes = Vec::new();
 
     routes.append(&mut commands::create_routes()?);
 
     routes.append(&mut request_info::create_routes());
 
-    Ok(Box::new(route::Router::new(routes)?))
+    Ok(routes)
+}
+
+pub fn create_handlers() -> anyhow::Result<Box<dyn RequestHandler>> {
+    let mut routes = base_routes()?;
+
+    let mut registered_routes = route::describe_routes(&routes)?;
+    registered_routes.push((Method::GET, route::full_path(Path::new("openapi.json"))?));
+
+    routes.append(&mut openapi::create_routes(registered_routes));
+
+    let router: Box<dyn RequestHandler> = Box::new(route::Router::new(routes)?);
+
+    Ok(Box::new(middleware::Layered::new(
+        middleware::create_middlewares(),
+        router,
+    )))
 }
@@ -0,0 +1,243 @@
+use async_trait::async_trait;
+
+use hyper::{
+    header::{
+        HeaderValue, ACCESS_CONTROL_ALLOW_HEADERS, ACCESS_CONTROL_ALLOW_METHODS,
+        ACCESS_CONTROL_ALLOW_ORIGIN, ACCESS_CONTROL_MAX_AGE, ACCESS_CONTROL_REQUEST_METHOD, ORIGIN,
+    },
+    http::Method,
+    Body, Response, StatusCode,
+};
+
+use crate::handlers::{middleware::Middleware, HttpRequest, RequestHandler};
+
+/// Answers `OPTIONS` preflight requests and injects `Access-Control-Allow-*` headers on
+/// responses. Allowed origins are matched individually and echoed back as the single
+/// `Access-Control-Allow-Origin` value, since browsers reject a comma-joined list there.
+pub struct Cors {
+    allow_all_origins: bool,
+    allowed_origins: Vec<String>,
+    allowed_methods: String,
+    allowed_headers: String,
+    max_age_seconds: u64,
+}
+
+impl Cors {
+    pub fn new() -> Self {
+        let cors_configuration = crate::config::instance().cors_configuration();
+
+        Self {
+            allow_all_origins: cors_configuration.allow_all_origins(),
+            allowed_origins: cors_configuration.allowed_origins().to_vec(),
+            allowed_methods: cors_configuration.allowed_methods().join(", "),
+            allowed_headers: cors_configuration.allowed_headers().join(", "),
+            max_age_seconds: cors_configuration.max_age_seconds(),
+        }
+    }
+
+    fn allow_origin_header_value(&self, request_origin: &str) -> Option<HeaderValue> {
+        if self.allow_all_origins {
+            return Some(HeaderValue::from_static("*"));
+        }
+
+        self.allowed_origins
+            .iter()
+            .any(|allowed_origin| allowed_origin == request_origin)
+            .then(|| HeaderValue::from_str(request_origin).ok())
+            .flatten()
+    }
+}
+
+impl Default for Cors {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Middleware for Cors {
+    async fn handle(&self, request: &HttpRequest, next: &dyn RequestHandler) -> Response<Body> {
+        let hyper_request = request.hyper_request();
+
+        let request_origin = hyper_request
+            .headers()
+            .get(ORIGIN)
+            .and_then(|value| value.to_str().ok())
+            .map(ToOwned::to_owned);
+
+        // A bare `OPTIONS` request with no `Origin`/`Access-Control-Request-Method` (e.g. a
+        // health-check probe, or a client probing an unregistered path) is not a CORS
+        // preflight and must fall through to `Timeout`/`Router` like any other method.
+        let is_preflight = hyper_request.method() == Method::OPTIONS
+            && request_origin.is_some()
+            && hyper_request
+                .headers()
+                .contains_key(ACCESS_CONTROL_REQUEST_METHOD);
+
+        let mut response = if is_preflight {
+            let mut response = Response::new(Body::empty());
+            *response.status_mut() = StatusCode::NO_CONTENT;
+            response
+        } else {
+            next.handle(request).await
+        };
+
+        let request_origin = match request_origin {
+            Some(request_origin) => request_origin,
+            None => return response,
+        };
+
+        let allow_origin_value = match self.allow_origin_header_value(&request_origin) {
+            Some(allow_origin_value) => allow_origin_value,
+            None => return response,
+        };
+
+        let headers = response.headers_mut();
+
+        headers.insert(ACCESS_CONTROL_ALLOW_ORIGIN, allow_origin_value);
+
+        if let Ok(allowed_methods_value) = HeaderValue::from_str(&self.allowed_methods) {
+            headers.insert(ACCESS_CONTROL_ALLOW_METHODS, allowed_methods_value);
+        }
+
+        if let Ok(allowed_headers_value) = HeaderValue::from_str(&self.allowed_headers) {
+            headers.insert(ACCESS_CONTROL_ALLOW_HEADERS, allowed_headers_value);
+        }
+
+        if is_preflight {
+            if let Ok(max_age_value) = HeaderValue::from_str(&self.max_age_seconds.to_string()) {
+                headers.insert(ACCESS_CONTROL_MAX_AGE, max_age_value);
+            }
+        }
+
+        response
+    }
+}
+
+#[cfg(test)]
+impl Cors {
+    fn for_test(allow_all_origins: bool, allowed_origins: Vec<String>) -> Self {
+        Self {
+            allow_all_origins,
+            allowed_origins,
+            allowed_methods: "GET, POST".to_owned(),
+            allowed_headers: "content-type".to_owned(),
+            max_age_seconds: 600,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::request::TestRequest;
+
+    struct OkHandler;
+
+    #[async_trait]
+    impl RequestHandler for OkHandler {
+        async fn handle(&self, _request: &HttpRequest) -> Response<Body> {
+            Response::new(Body::empty())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_allowed_origin_echoed_back() {
+        let cors = Cors::for_test(false, vec!["https://example.com".to_owned()]);
+
+        let response = TestRequest::new()
+            .header("origin", "https://example.com")
+            .run_middleware(&cors, &OkHandler)
+            .await;
+
+        assert_eq!(
+            response
+                .headers()
+                .get(ACCESS_CONTROL_ALLOW_ORIGIN)
+                .and_then(|value| value.to_str().ok()),
+            Some("https://example.com")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_disallowed_origin_gets_no_header() {
+        let cors = Cors::for_test(false, vec!["https://example.com".to_owned()]);
+
+        let response = TestRequest::new()
+            .header("origin", "https://evil.example")
+            .run_middleware(&cors, &OkHandler)
+            .await;
+
+        assert!(response.headers().get(ACCESS_CONTROL_ALLOW_ORIGIN).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_allow_all_origins() {
+        let cors = Cors::for_test(true, vec![]);
+
+        let response = TestRequest::new()
+            .header("origin", "https://anything.example")
+            .run_middleware(&cors, &OkHandler)
+            .await;
+
+        assert_eq!(
+            response
+                .headers()
+                .get(ACCESS_CONTROL_ALLOW_ORIGIN)
+                .and_then(|value| value.to_str().ok()),
+            Some("*")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_preflight_short_circuits_with_204() {
+        let cors = Cors::for_test(true, vec![]);
+
+        let response = TestRequest::new()
+            .method(Method::OPTIONS)
+            .header("origin", "https://example.com")
+            .header("access-control-request-method", "POST")
+            .run_middleware(&cors, &OkHandler)
+            .await;
+
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert!(response.headers().get(ACCESS_CONTROL_MAX_AGE).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_bare_options_without_origin_falls_through() {
+        let cors = Cors::for_test(true, vec![]);
+
+        let response = TestRequest::new()
+            .method(Method::OPTIONS)
+            .run_middleware(&cors, &OkHandler)
+            .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_options_on_unregistered_path_reaches_next() {
+        struct NotFoundHandler;
+
+        #[async_trait]
+        impl RequestHandler for NotFoundHandler {
+            async fn handle(&self, _request: &HttpRequest) -> Response<Body> {
+                let mut response = Response::new(Body::empty());
+                *response.status_mut() = StatusCode::NOT_FOUND;
+                response
+            }
+        }
+
+        let cors = Cors::for_test(true, vec![]);
+
+        let response = TestRequest::new()
+            .method(Method::OPTIONS)
+            .uri("/does-not-exist")
+            .run_middleware(&cors, &NotFoundHandler)
+            .await;
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,146 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use hyper::{Body, Response};
+
+use crate::handlers::{cors::Cors, timeout::Timeout, HttpRequest, RequestHandler};
+
+/// A composable wrapper around a [`RequestHandler`]. Implementations may inspect or
+/// modify the request/response, short-circuit by not calling `next`, or delegate to
+/// `next` to continue the chain.
+#[async_trait]
+pub trait Middleware: Send + Sync {
+    async fn handle(&self, request: &HttpRequest, next: &dyn RequestHandler) -> Response<Body>;
+}
+
+/// Wraps `inner` with a stack of [`Middleware`], invoked outer-to-inner in registration
+/// order so that the first middleware in the list is the outermost layer.
+pub struct Layered {
+    middlewares: Vec<Arc<dyn Middleware>>,
+    inner: Box<dyn RequestHandler>,
+}
+
+impl Layered {
+    pub fn new(middlewares: Vec<Arc<dyn Middleware>>, inner: Box<dyn RequestHandler>) -> Self {
+        Self { middlewares, inner }
+    }
+}
+
+struct Next<'a> {
+    middlewares: &'a [Arc<dyn Middleware>],
+    inner: &'a dyn RequestHandler,
+}
+
+#[async_trait]
+impl<'a> RequestHandler for Next<'a> {
+    async fn handle(&self, request: &HttpRequest) -> Response<Body> {
+        match self.middlewares.split_first() {
+            None => self.inner.handle(request).await,
+            Some((middleware, rest)) => {
+                let next = Next {
+                    middlewares: rest,
+                    inner: self.inner,
+                };
+                middleware.handle(request, &next).await
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl RequestHandler for Layered {
+    async fn handle(&self, request: &HttpRequest) -> Response<Body> {
+        let next = Next {
+            middlewares: &self.middlewares,
+            inner: self.inner.as_ref(),
+        };
+        next.handle(request).await
+    }
+}
+
+/// Middleware stack applied to the whole [`crate::handlers::route::Router`], outermost first.
+/// Individual middleware implementations register themselves here as they're added.
+pub fn create_middlewares() -> Vec<Arc<dyn Middleware>> {
+    vec![Arc::new(Cors::new()), Arc::new(Timeout::new())]
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    use crate::request::TestRequest;
+
+    struct RecordingMiddleware {
+        name: &'static str,
+        trace: Arc<Mutex<Vec<&'static str>>>,
+    }
+
+    #[async_trait]
+    impl Middleware for RecordingMiddleware {
+        async fn handle(&self, request: &HttpRequest, next: &dyn RequestHandler) -> Response<Body> {
+            self.trace.lock().unwrap().push(self.name);
+            let response = next.handle(request).await;
+            self.trace.lock().unwrap().push(self.name);
+            response
+        }
+    }
+
+    struct RecordingHandler {
+        trace: Arc<Mutex<Vec<&'static str>>>,
+    }
+
+    #[async_trait]
+    impl RequestHandler for RecordingHandler {
+        async fn handle(&self, _request: &HttpRequest) -> Response<Body> {
+            self.trace.lock().unwrap().push("inner");
+            Response::new(Body::empty())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_middlewares_invoked_outer_to_inner() {
+        let trace = Arc::new(Mutex::new(Vec::new()));
+
+        let middlewares: Vec<Arc<dyn Middleware>> = vec![
+            Arc::new(RecordingMiddleware {
+                name: "outer",
+                trace: trace.clone(),
+            }),
+            Arc::new(RecordingMiddleware {
+                name: "inner-middleware",
+                trace: trace.clone(),
+            }),
+        ];
+
+        let inner: Box<dyn RequestHandler> = Box::new(RecordingHandler {
+            trace: trace.clone(),
+        });
+
+        let layered = Layered::new(middlewares, inner);
+
+        TestRequest::new().run(&layered).await;
+
+        assert_eq!(
+            *trace.lock().unwrap(),
+            vec!["outer", "inner-middleware", "inner", "inner-middleware", "outer"],
+        );
+    }
+
+    #[tokio::test]
+    async fn test_no_middlewares_calls_inner_directly() {
+        let trace = Arc::new(Mutex::new(Vec::new()));
+
+        let inner: Box<dyn RequestHandler> = Box::new(RecordingHandler {
+            trace: trace.clone(),
+        });
+
+        let layered = Layered::new(vec![], inner);
+
+        TestRequest::new().run(&layered).await;
+
+        assert_eq!(*trace.lock().unwrap(), vec!["inner"]);
+    }
+}
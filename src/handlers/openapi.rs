@@ -0,0 +1,110 @@
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+
+use hyper::{http::Method, Body, Response};
+
+use serde_json::{json, Value};
+
+use crate::handlers::{route::RouteInfo, utils::build_json_response, HttpRequest, RequestHandler};
+
+/// Serves a minimal OpenAPI 3.0 document describing every route registered with the
+/// `Router`, so clients can codegen against these endpoints without a hand-maintained spec.
+struct OpenApiHandler {
+    document: Value,
+}
+
+/// Translates a `route::Router` path (which may contain `:name`/`*name` segments) into
+/// OpenAPI's `{name}` path-templating syntax. A `*wildcard` tail is mapped to a single
+/// `{name}` template parameter too: OpenAPI has no native multi-segment wildcard, so this
+/// is the closest representable approximation rather than a fully faithful match.
+fn openapi_path_template(path: &str) -> String {
+    path.split('/')
+        .map(|segment| {
+            segment
+                .strip_prefix(':')
+                .or_else(|| segment.strip_prefix('*'))
+                .map_or_else(|| segment.to_owned(), |name| format!("{{{}}}", name))
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+impl OpenApiHandler {
+    fn new(registered_routes: Vec<(Method, String)>) -> Self {
+        let mut paths = serde_json::Map::new();
+
+        for (method, path) in registered_routes {
+            let path_item = paths
+                .entry(openapi_path_template(&path))
+                .or_insert_with(|| json!({}));
+
+            path_item[method.as_str().to_lowercase()] = json!({
+                "responses": {
+                    "200": {
+                        "description": "Successful response",
+                    },
+                },
+            });
+        }
+
+        let document = json!({
+            "openapi": "3.0.3",
+            "info": {
+                "title": "rust-hyper-notcgi",
+                "version": "1.0",
+            },
+            "paths": Value::Object(paths),
+        });
+
+        Self { document }
+    }
+}
+
+#[async_trait]
+impl RequestHandler for OpenApiHandler {
+    async fn handle(&self, _request: &HttpRequest) -> Response<Body> {
+        build_json_response(self.document.clone())
+    }
+}
+
+pub fn create_routes(registered_routes: Vec<(Method, String)>) -> Vec<RouteInfo> {
+    vec![RouteInfo {
+        method: &Method::GET,
+        path_suffix: PathBuf::from("openapi.json"),
+        handler: Box::new(OpenApiHandler::new(registered_routes)),
+    }]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_param_and_wildcard_segments_are_templated() {
+        assert_eq!(openapi_path_template("/user/:id"), "/user/{id}");
+        assert_eq!(openapi_path_template("/static/*rest"), "/static/{rest}");
+        assert_eq!(openapi_path_template("/request_info"), "/request_info");
+    }
+
+    #[tokio::test]
+    async fn test_document_shape() {
+        let handler = OpenApiHandler::new(vec![
+            (Method::GET, "/user/:id".to_owned()),
+            (Method::POST, "/user/:id".to_owned()),
+            (Method::GET, "/openapi.json".to_owned()),
+        ]);
+
+        let response = crate::request::TestRequest::new().run(&handler).await;
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let document: Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(document["openapi"], "3.0.3");
+        assert!(document["paths"]["/user/{id}"]["get"]["responses"]["200"].is_object());
+        assert!(document["paths"]["/user/{id}"]["post"]["responses"]["200"].is_object());
+        assert!(document["paths"]["/openapi.json"]["get"].is_object());
+        // Raw `:id` syntax must never leak into the generated document.
+        assert!(document["paths"].get("/user/:id").is_none());
+    }
+}
@@ -2,7 +2,7 @@ use std::{collections::BTreeMap, path::PathBuf};
 
 use async_trait::async_trait;
 
-use hyper::{http::Method, http::Version, Body, Response};
+use hyper::{http::Method, http::Version, HeaderMap, Body, Response};
 
 use serde::Serialize;
 
@@ -16,6 +16,44 @@ struct RequestInfoResponse<'a> {
     version: &'a str,
     request_uri_path: &'a str,
     http_headers: BTreeMap<&'a str, &'a str>,
+    query_parameters: BTreeMap<String, String>,
+    cookies: BTreeMap<String, String>,
+}
+
+fn parse_query_parameters(uri: &hyper::http::Uri) -> BTreeMap<String, String> {
+    uri.query()
+        .map(|query| {
+            form_urlencoded::parse(query.as_bytes())
+                .into_owned()
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Parses the `Cookie` header(s) into name/value pairs. Unlike a query string, cookie
+/// values follow RFC 6265 and have no `+`-means-space convention, so pairs are
+/// percent-decoded directly rather than via `form_urlencoded`, which would mangle a
+/// literal `+` in a cookie value into a space. If a cookie name is repeated, the last
+/// value wins.
+fn parse_cookies(headers: &HeaderMap) -> BTreeMap<String, String> {
+    headers
+        .get_all(hyper::header::COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(';'))
+        .filter_map(|cookie_pair| {
+            let (name, value) = cookie_pair.trim().split_once('=')?;
+
+            let name = percent_encoding::percent_decode_str(name.trim())
+                .decode_utf8_lossy()
+                .into_owned();
+            let value = percent_encoding::percent_decode_str(value.trim())
+                .decode_utf8_lossy()
+                .into_owned();
+
+            Some((name, value))
+        })
+        .collect()
 }
 
 struct RequestInfoHandler {}
@@ -51,6 +89,8 @@ impl RequestHandler for RequestInfoHandler {
                 .iter()
                 .map(|(key, value)| (key.as_str(), value.to_str().unwrap_or("[Unknown]")))
                 .collect(),
+            query_parameters: parse_query_parameters(hyper_request.uri()),
+            cookies: parse_cookies(hyper_request.headers()),
         };
 
         build_json_response(response)
@@ -64,3 +104,59 @@ pub fn create_routes() -> Vec<RouteInfo> {
         handler: Box::new(RequestInfoHandler::new()),
     }]
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_query_parameters() {
+        let uri: hyper::http::Uri = "/request_info?name=John%20Doe&empty=&name2=value2"
+            .parse()
+            .unwrap();
+
+        let query_parameters = parse_query_parameters(&uri);
+
+        assert_eq!(query_parameters.get("name").map(String::as_str), Some("John Doe"));
+        assert_eq!(query_parameters.get("empty").map(String::as_str), Some(""));
+        assert_eq!(query_parameters.get("name2").map(String::as_str), Some("value2"));
+    }
+
+    #[test]
+    fn test_parse_query_parameters_no_query() {
+        let uri: hyper::http::Uri = "/request_info".parse().unwrap();
+
+        assert!(parse_query_parameters(&uri).is_empty());
+    }
+
+    #[test]
+    fn test_parse_cookies() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            hyper::header::COOKIE,
+            "session=abc123; name=John%20Doe; name=Jane%20Doe".parse().unwrap(),
+        );
+
+        let cookies = parse_cookies(&headers);
+
+        assert_eq!(cookies.get("session").map(String::as_str), Some("abc123"));
+        assert_eq!(cookies.get("name").map(String::as_str), Some("Jane Doe"));
+    }
+
+    #[test]
+    fn test_parse_cookies_no_header() {
+        let headers = HeaderMap::new();
+
+        assert!(parse_cookies(&headers).is_empty());
+    }
+
+    #[test]
+    fn test_parse_cookies_literal_plus_is_preserved() {
+        let mut headers = HeaderMap::new();
+        headers.insert(hyper::header::COOKIE, "id=a+b".parse().unwrap());
+
+        let cookies = parse_cookies(&headers);
+
+        assert_eq!(cookies.get("id").map(String::as_str), Some("a+b"));
+    }
+}
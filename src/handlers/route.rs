@@ -1,4 +1,4 @@
-use std::{borrow::Cow, collections::HashMap, path::PathBuf};
+use std::{collections::HashMap, path::PathBuf};
 
 use anyhow::Context;
 
@@ -6,7 +6,10 @@ use async_trait::async_trait;
 
 use hyper::{http::Method, Body, Response};
 
-use crate::handlers::{utils::build_status_code_response, HttpRequest, RequestHandler};
+use crate::{
+    handlers::{utils::build_status_code_response, HttpRequest, RequestHandler},
+    request::Params,
+};
 
 pub struct RouteInfo {
     pub method: &'static Method,
@@ -14,72 +17,182 @@ pub struct RouteInfo {
     pub handler: Box<dyn RequestHandler>,
 }
 
-#[derive(Clone, Debug, Eq, PartialEq, Hash)]
-struct RouteKey<'a> {
-    method: &'a Method,
-    path: Cow<'a, str>,
+#[derive(Default)]
+struct TrieNode {
+    literal_children: HashMap<String, TrieNode>,
+    param_child: Option<(String, Box<TrieNode>)>,
+    wildcard_child: Option<(String, Box<dyn RequestHandler>)>,
+    handler: Option<Box<dyn RequestHandler>>,
 }
 
-impl<'a> From<&'a HttpRequest> for RouteKey<'a> {
-    fn from(http_request: &'a HttpRequest) -> Self {
-        Self {
-            method: http_request.hyper_request().method(),
-            path: Cow::from(http_request.hyper_request().uri().path()),
+impl TrieNode {
+    fn insert(&mut self, segments: &[String], handler: Box<dyn RequestHandler>) -> anyhow::Result<()> {
+        let (segment, rest) = match segments.split_first() {
+            None => {
+                if self.handler.is_some() {
+                    anyhow::bail!("TrieNode::insert error: duplicate route registration");
+                }
+                self.handler = Some(handler);
+                return Ok(());
+            }
+            Some(split) => split,
+        };
+
+        if let Some(wildcard_name) = segment.strip_prefix('*') {
+            if !rest.is_empty() {
+                anyhow::bail!(
+                    "TrieNode::insert error: wildcard segment '*{}' must be the last path segment",
+                    wildcard_name,
+                );
+            }
+            if self.wildcard_child.is_some() {
+                anyhow::bail!(
+                    "TrieNode::insert error: duplicate wildcard route registration at '*{}'",
+                    wildcard_name,
+                );
+            }
+            self.wildcard_child = Some((wildcard_name.to_owned(), handler));
+            return Ok(());
         }
+
+        if let Some(param_name) = segment.strip_prefix(':') {
+            return match &mut self.param_child {
+                Some((existing_param_name, node)) => {
+                    if existing_param_name != param_name {
+                        anyhow::bail!(
+                            "TrieNode::insert error: conflicting param names ':{}' and ':{}' at the same path position",
+                            existing_param_name,
+                            param_name,
+                        );
+                    }
+                    node.insert(rest, handler)
+                }
+                None => {
+                    let mut node = TrieNode::default();
+                    node.insert(rest, handler)?;
+                    self.param_child = Some((param_name.to_owned(), Box::new(node)));
+                    Ok(())
+                }
+            };
+        }
+
+        self.literal_children
+            .entry(segment.clone())
+            .or_insert_with(TrieNode::default)
+            .insert(rest, handler)
     }
+
+    fn find(&self, segments: &[&str], params: &mut Params) -> Option<&dyn RequestHandler> {
+        let (segment, rest) = match segments.split_first() {
+            None => return self.handler.as_deref(),
+            Some(split) => split,
+        };
+
+        if let Some(child) = self.literal_children.get(*segment) {
+            if let Some(handler) = child.find(rest, params) {
+                return Some(handler);
+            }
+        }
+
+        if let Some((param_name, node)) = &self.param_child {
+            params.insert(param_name.clone(), (*segment).to_owned());
+            if let Some(handler) = node.find(rest, params) {
+                return Some(handler);
+            }
+            params.remove(param_name);
+        }
+
+        if let Some((wildcard_name, handler)) = &self.wildcard_child {
+            params.insert(wildcard_name.clone(), segments.join("/"));
+            return Some(handler.as_ref());
+        }
+
+        None
+    }
+}
+
+fn path_segments(path: &str) -> Vec<&str> {
+    path.split('/').filter(|segment| !segment.is_empty()).collect()
+}
+
+/// Joins `path_suffix` onto the configured context, e.g. `"foo"` -> `"/context/foo"`.
+pub fn full_path(path_suffix: &std::path::Path) -> anyhow::Result<String> {
+    let context_configuration = crate::config::instance().context_configuration();
+
+    let uri_pathbuf = PathBuf::from(context_configuration.context()).join(path_suffix);
+
+    uri_pathbuf
+        .to_str()
+        .map(ToOwned::to_owned)
+        .with_context(|| {
+            format!(
+                "route::full_path error: uri_pathbuf.to_str error uri_pathbuf = '{:?}'",
+                uri_pathbuf,
+            )
+        })
+}
+
+/// The full, context-prefixed `(method, path)` table `routes` would register, in order.
+/// Used by `create_handlers` to build the table passed to `openapi::create_routes`
+/// without building a `Router`.
+pub fn describe_routes(routes: &[RouteInfo]) -> anyhow::Result<Vec<(Method, String)>> {
+    routes
+        .iter()
+        .map(|route| Ok((route.method.clone(), full_path(&route.path_suffix)?)))
+        .collect()
 }
 
 pub struct Router {
-    route_key_to_handler: HashMap<RouteKey<'static>, Box<dyn RequestHandler>>,
+    method_to_trie: HashMap<Method, TrieNode>,
 }
 
 impl Router {
     pub fn new(routes: Vec<RouteInfo>) -> anyhow::Result<Self> {
-        let mut router = Self {
-            route_key_to_handler: HashMap::with_capacity(routes.len()),
-        };
-
-        let context_configuration = crate::config::instance().context_configuration();
+        let mut method_to_trie: HashMap<Method, TrieNode> = HashMap::new();
 
         for route in routes {
-            let uri_pathbuf =
-                PathBuf::from(context_configuration.context()).join(route.path_suffix);
+            let path = full_path(&route.path_suffix)?;
 
-            let path = uri_pathbuf
-                .to_str()
-                .with_context(|| {
-                    format!(
-                        "Router::new error: uri_pathbuf.to_str error uri_pathbuf = '{:?}'",
-                        uri_pathbuf,
-                    )
-                })?
-                .to_owned();
-
-            let key = RouteKey {
-                method: route.method,
-                path: Cow::from(path),
-            };
+            let segments: Vec<String> = path_segments(&path)
+                .into_iter()
+                .map(ToOwned::to_owned)
+                .collect();
 
-            if router
-                .route_key_to_handler
-                .insert(key.clone(), route.handler)
-                .is_some()
-            {
-                anyhow::bail!("Router::new error: collision in router key = {:?}", key);
-            }
+            let method = route.method.clone();
+
+            method_to_trie
+                .entry(method.clone())
+                .or_insert_with(TrieNode::default)
+                .insert(&segments, route.handler)
+                .with_context(|| {
+                    format!("Router::new error: method = {} path = '{}'", method, path)
+                })?;
         }
-        Ok(router)
+
+        Ok(Self { method_to_trie })
     }
 }
 
 #[async_trait]
 impl RequestHandler for Router {
     async fn handle(&self, request: &HttpRequest) -> Response<Body> {
-        let handler_option = self.route_key_to_handler.get(&RouteKey::from(request));
+        let hyper_request = request.hyper_request();
+
+        let segments = path_segments(hyper_request.uri().path());
+
+        let mut params = Params::new();
+
+        let handler = self
+            .method_to_trie
+            .get(hyper_request.method())
+            .and_then(|trie| trie.find(&segments, &mut params));
 
-        match handler_option {
+        match handler {
             None => build_status_code_response(hyper::http::StatusCode::NOT_FOUND),
-            Some(handler) => handler.handle(&request).await,
+            Some(handler) => {
+                request.set_params(params);
+                handler.handle(request).await
+            }
         }
     }
 }
@@ -88,67 +201,95 @@ impl RequestHandler for Router {
 mod test {
     use super::*;
 
+    fn insert(trie: &mut TrieNode, path: &str, handler: Box<dyn RequestHandler>) -> anyhow::Result<()> {
+        let segments: Vec<String> = path_segments(path).into_iter().map(ToOwned::to_owned).collect();
+        trie.insert(&segments, handler)
+    }
+
+    struct TestHandler(&'static str);
+
+    #[async_trait]
+    impl RequestHandler for TestHandler {
+        async fn handle(&self, _request: &HttpRequest) -> Response<Body> {
+            build_status_code_response(hyper::http::StatusCode::OK)
+        }
+    }
+
     #[test]
-    fn test_route_key_equality() {
-        assert_eq!(
-            RouteKey {
-                method: &Method::GET,
-                path: Cow::Borrowed("/test"),
-            },
-            RouteKey {
-                method: &Method::GET,
-                path: Cow::Owned("/test".to_owned()),
-            }
-        );
-
-        assert_ne!(
-            RouteKey {
-                method: &Method::GET,
-                path: Cow::Borrowed("/test"),
-            },
-            RouteKey {
-                method: &Method::PUT,
-                path: Cow::Owned("/test".to_owned()),
-            }
-        );
-
-        assert_ne!(
-            RouteKey {
-                method: &Method::GET,
-                path: Cow::Borrowed("/nottest"),
-            },
-            RouteKey {
-                method: &Method::GET,
-                path: Cow::Owned("/test".to_owned()),
-            }
-        );
+    fn test_literal_match() {
+        let mut trie = TrieNode::default();
+        insert(&mut trie, "/user/list", Box::new(TestHandler("list"))).unwrap();
+
+        let mut params = Params::new();
+        let segments = path_segments("/user/list");
+        assert!(trie.find(&segments, &mut params).is_some());
+        assert!(params.is_empty());
     }
 
     #[test]
-    fn test_route_key_hash() {
-        use std::{
-            collections::hash_map::DefaultHasher,
-            hash::{Hash, Hasher},
-        };
+    fn test_param_capture() {
+        let mut trie = TrieNode::default();
+        insert(&mut trie, "/user/:id", Box::new(TestHandler("id"))).unwrap();
 
-        let key1 = RouteKey {
-            method: &Method::GET,
-            path: Cow::Borrowed("/test"),
-        };
+        let mut params = Params::new();
+        let segments = path_segments("/user/42");
+        assert!(trie.find(&segments, &mut params).is_some());
+        assert_eq!(params.get("id"), Some(&"42".to_owned()));
+    }
 
-        let key2 = RouteKey {
-            method: &Method::GET,
-            path: Cow::Owned("/test".to_owned()),
-        };
+    #[test]
+    fn test_literal_preferred_over_param() {
+        let mut trie = TrieNode::default();
+        insert(&mut trie, "/user/list", Box::new(TestHandler("list"))).unwrap();
+        insert(&mut trie, "/user/:id", Box::new(TestHandler("id"))).unwrap();
 
-        let mut s = DefaultHasher::new();
-        key1.hash(&mut s);
-        let key1_hash = s.finish();
+        let mut params = Params::new();
+        let segments = path_segments("/user/list");
+        assert!(trie.find(&segments, &mut params).is_some());
+        assert!(params.is_empty());
+
+        let mut params = Params::new();
+        let segments = path_segments("/user/42");
+        assert!(trie.find(&segments, &mut params).is_some());
+        assert_eq!(params.get("id"), Some(&"42".to_owned()));
+    }
+
+    #[test]
+    fn test_wildcard_tail() {
+        let mut trie = TrieNode::default();
+        insert(&mut trie, "/static/*rest", Box::new(TestHandler("static"))).unwrap();
+
+        let mut params = Params::new();
+        let segments = path_segments("/static/css/site.css");
+        assert!(trie.find(&segments, &mut params).is_some());
+        assert_eq!(params.get("rest"), Some(&"css/site.css".to_owned()));
+    }
 
-        let mut s = DefaultHasher::new();
-        key2.hash(&mut s);
-        let key2_hash = s.finish();
+    #[test]
+    fn test_conflicting_param_names_bail() {
+        let mut trie = TrieNode::default();
+        insert(&mut trie, "/user/:id", Box::new(TestHandler("id"))).unwrap();
+
+        let result = insert(&mut trie, "/user/:name", Box::new(TestHandler("name")));
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_test_request_runs_handler() {
+        let response = crate::request::TestRequest::new()
+            .uri("/user/42")
+            .run(&TestHandler("id"))
+            .await;
+
+        assert_eq!(response.status(), hyper::http::StatusCode::OK);
+    }
+
+    #[test]
+    fn test_duplicate_route_bail() {
+        let mut trie = TrieNode::default();
+        insert(&mut trie, "/user/list", Box::new(TestHandler("list"))).unwrap();
 
-        assert_eq!(key1_hash, key2_hash);
+        let result = insert(&mut trie, "/user/list", Box::new(TestHandler("list2")));
+        assert!(result.is_err());
     }
 }
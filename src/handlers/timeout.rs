@@ -0,0 +1,101 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use hyper::{http::StatusCode, Body, Response};
+
+use crate::handlers::{middleware::Middleware, utils::build_status_code_response, HttpRequest, RequestHandler};
+
+/// Bounds how long a handler may run before the middleware gives up and returns
+/// `408 Request Timeout`, so a hung handler (e.g. a `commands` handler shelling out to a
+/// stuck subprocess) can't hold a connection open indefinitely.
+pub struct Timeout {
+    duration: Duration,
+}
+
+impl Timeout {
+    pub fn new() -> Self {
+        let timeout_configuration = crate::config::instance().timeout_configuration();
+
+        Self {
+            duration: timeout_configuration.request_timeout(),
+        }
+    }
+}
+
+impl Default for Timeout {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Middleware for Timeout {
+    async fn handle(&self, request: &HttpRequest, next: &dyn RequestHandler) -> Response<Body> {
+        match tokio::time::timeout(self.duration, next.handle(request)).await {
+            Ok(response) => response,
+            Err(_elapsed) => build_status_code_response(StatusCode::REQUEST_TIMEOUT),
+        }
+    }
+}
+
+#[cfg(test)]
+impl Timeout {
+    fn for_test(duration: Duration) -> Self {
+        Self { duration }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::request::TestRequest;
+
+    struct OkHandler;
+
+    #[async_trait]
+    impl RequestHandler for OkHandler {
+        async fn handle(&self, _request: &HttpRequest) -> Response<Body> {
+            build_status_code_response(StatusCode::OK)
+        }
+    }
+
+    struct SlowHandler {
+        sleep_duration: Duration,
+    }
+
+    #[async_trait]
+    impl RequestHandler for SlowHandler {
+        async fn handle(&self, _request: &HttpRequest) -> Response<Body> {
+            tokio::time::sleep(self.sleep_duration).await;
+            build_status_code_response(StatusCode::OK)
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_handler_within_timeout_passes_through() {
+        let timeout = Timeout::for_test(Duration::from_secs(5));
+
+        let response = TestRequest::new()
+            .run_middleware(&timeout, &OkHandler)
+            .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_slow_handler_returns_408() {
+        let timeout = Timeout::for_test(Duration::from_secs(1));
+
+        let slow_handler = SlowHandler {
+            sleep_duration: Duration::from_secs(5),
+        };
+
+        let response = TestRequest::new()
+            .run_middleware(&timeout, &slow_handler)
+            .await;
+
+        assert_eq!(response.status(), StatusCode::REQUEST_TIMEOUT);
+    }
+}
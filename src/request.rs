@@ -0,0 +1,165 @@
+use std::{collections::BTreeMap, sync::OnceLock};
+
+use hyper::{http::Method, http::Version, Body, Request};
+
+/// Named/wildcard path segment captures populated by `route::Router` once a route matches.
+pub type Params = BTreeMap<String, String>;
+
+pub struct HttpRequest {
+    connection_id: u64,
+    request_id: u64,
+    hyper_request: Request<Body>,
+    params: OnceLock<Params>,
+}
+
+impl HttpRequest {
+    pub fn new(connection_id: u64, request_id: u64, hyper_request: Request<Body>) -> Self {
+        Self {
+            connection_id,
+            request_id,
+            hyper_request,
+            params: OnceLock::new(),
+        }
+    }
+
+    pub fn connection_id(&self) -> &u64 {
+        &self.connection_id
+    }
+
+    pub fn request_id(&self) -> &u64 {
+        &self.request_id
+    }
+
+    pub fn hyper_request(&self) -> &Request<Body> {
+        &self.hyper_request
+    }
+
+    /// Set by `route::Router` once a route match produces path parameter captures.
+    /// A no-op if params were already set for this request.
+    pub fn set_params(&self, params: Params) {
+        let _ = self.params.set(params);
+    }
+
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .get()
+            .and_then(|params| params.get(name))
+            .map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+pub struct TestRequest {
+    connection_id: u64,
+    request_id: u64,
+    method: Method,
+    uri: String,
+    version: Version,
+    headers: Vec<(String, String)>,
+}
+
+#[cfg(test)]
+impl TestRequest {
+    pub fn new() -> Self {
+        Self {
+            connection_id: 0,
+            request_id: 0,
+            method: Method::GET,
+            uri: "/".to_owned(),
+            version: Version::HTTP_11,
+            headers: Vec::new(),
+        }
+    }
+
+    pub fn connection_id(mut self, connection_id: u64) -> Self {
+        self.connection_id = connection_id;
+        self
+    }
+
+    pub fn request_id(mut self, request_id: u64) -> Self {
+        self.request_id = request_id;
+        self
+    }
+
+    pub fn method(mut self, method: Method) -> Self {
+        self.method = method;
+        self
+    }
+
+    pub fn uri(mut self, uri: impl Into<String>) -> Self {
+        self.uri = uri.into();
+        self
+    }
+
+    pub fn version(mut self, version: Version) -> Self {
+        self.version = version;
+        self
+    }
+
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    pub fn build(self) -> HttpRequest {
+        let mut builder = Request::builder()
+            .method(self.method)
+            .uri(self.uri)
+            .version(self.version);
+
+        for (name, value) in &self.headers {
+            builder = builder.header(name, value);
+        }
+
+        let hyper_request = builder
+            .body(Body::empty())
+            .expect("TestRequest::build error: failed to build hyper::Request");
+
+        HttpRequest::new(self.connection_id, self.request_id, hyper_request)
+    }
+
+    pub async fn run(self, handler: &dyn crate::handlers::RequestHandler) -> hyper::Response<Body> {
+        let http_request = self.build();
+        handler.handle(&http_request).await
+    }
+
+    /// Drives a single `Middleware` directly against `next`, without needing a full
+    /// `Layered` stack — handy for unit-testing one middleware in isolation.
+    pub async fn run_middleware(
+        self,
+        middleware: &dyn crate::handlers::middleware::Middleware,
+        next: &dyn crate::handlers::RequestHandler,
+    ) -> hyper::Response<Body> {
+        let http_request = self.build();
+        middleware.handle(&http_request, next).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_test_request_builder_sets_fields() {
+        let http_request = TestRequest::new()
+            .connection_id(1)
+            .request_id(2)
+            .method(Method::POST)
+            .uri("/user/42")
+            .header("x-test", "1")
+            .build();
+
+        assert_eq!(*http_request.connection_id(), 1);
+        assert_eq!(*http_request.request_id(), 2);
+        assert_eq!(http_request.hyper_request().method(), Method::POST);
+        assert_eq!(http_request.hyper_request().uri().path(), "/user/42");
+        assert_eq!(
+            http_request
+                .hyper_request()
+                .headers()
+                .get("x-test")
+                .and_then(|value| value.to_str().ok()),
+            Some("1")
+        );
+    }
+}